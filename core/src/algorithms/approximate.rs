@@ -1,13 +1,54 @@
 use crate::{
-    primitives::{Arc, Line},
+    ops,
+    primitives::{Arc, CubicBezier, EllipticalArc, Line, QuadraticBezier},
     Angle,
 };
 use euclid::Point2D;
 use std::{
     iter,
     iter::{Chain, Once},
+    vec,
 };
 
+/// Work out how many line segments are needed to approximate a sweep of
+/// `sweep_angle` around a curve of radius `radius` to within `tolerance`,
+/// and the angular step between each of them.
+///
+/// Draw a chord between points A and B on a circle with centre C. Draw a
+/// line which bisects the angle ACB and intersects with the chord at point
+/// D. The distance from D to the arc is our "quality" (i.e. `|CD| +
+/// quality = radius`).
+///
+/// From the triangle DCB:
+///   cos(θ/2) = |CD|/R
+///   cos(θ/2) = 1 - quality/R
+///
+/// where θ is the angle swept by a chord with the desired "quality".
+///
+/// # line segments to approximate with the specified quality:
+///   N = ⌈SweepAngle/θ⌉
+fn steps_for_radius(radius: f64, sweep_angle: Angle, tolerance: f64) -> (usize, Angle) {
+    if tolerance <= 0.0 || radius <= tolerance {
+        return (1, sweep_angle);
+    }
+
+    let cos_theta_on_two = 1.0 - tolerance / radius;
+    let theta = ops::acos(cos_theta_on_two) * 2.0;
+    let line_segment_count = sweep_angle.get().abs() / theta;
+
+    // make sure we always have at least 2 points
+    let line_segment_count = f64::max(line_segment_count, 2.0);
+    let steps = line_segment_count.ceil().abs() as usize;
+
+    // Divide by the *ceiled* count, not the raw one: the iterator always
+    // takes `steps` steps, so the step size must be the one that lands
+    // exactly on `sweep_angle` after that many steps, not an earlier one
+    // that overshoots it.
+    let actual_step = sweep_angle / steps as f64;
+
+    (steps, actual_step)
+}
+
 /// Approximate a shape with a bunch of [`Point2D`]s.
 pub trait Approximate<Space> {
     /// An iterator over the approximated vertices.
@@ -16,6 +57,20 @@ pub trait Approximate<Space> {
     /// Approximate the shape, ensuring the resulting path is within `tolerance`
     /// units of the original.
     fn approximate(&self, tolerance: f64) -> Self::Iter;
+
+    /// Approximate the shape into a caller-owned buffer instead of
+    /// allocating a fresh iterator's worth of state.
+    ///
+    /// This appends to `out` rather than clearing it, so callers flattening
+    /// several shapes into one path can call this repeatedly. The default
+    /// implementation just drains [`approximate`](Approximate::approximate);
+    /// shapes whose approximation is naturally buffer-based (e.g. the
+    /// recursively-flattened Bézier curves) override it and implement
+    /// `approximate` in terms of it instead, so both share the same
+    /// step-count/flatness logic.
+    fn approximate_into(&self, tolerance: f64, out: &mut Vec<Point2D<f64, Space>>) {
+        out.extend(self.approximate(tolerance));
+    }
 }
 
 impl<'a, Space, A: Approximate<Space> + ?Sized> Approximate<Space> for &'a A {
@@ -24,6 +79,10 @@ impl<'a, Space, A: Approximate<Space> + ?Sized> Approximate<Space> for &'a A {
     fn approximate(&self, tolerance: f64) -> Self::Iter {
         (*self).approximate(tolerance)
     }
+
+    fn approximate_into(&self, tolerance: f64, out: &mut Vec<Point2D<f64, Space>>) {
+        (*self).approximate_into(tolerance, out);
+    }
 }
 
 impl<Space> Approximate<Space> for Point2D<f64, Space> {
@@ -46,34 +105,7 @@ impl<Space> Approximate<Space> for Arc<Space> {
     type Iter = ApproximatedArc<Space>;
 
     fn approximate(&self, tolerance: f64) -> Self::Iter {
-        // Draw a chord between points A and B on a circle with centre C.
-        // Draw a line which bisects the angle ACB and intersects with the
-        // chord at point D.
-        // The distance from D to the arc is our "quality"
-        // (i.e. |CD| + quality = radius).
-        //
-        // From the triangle DCB:
-        //   cos(θ/2) = |CD|/R
-        //   cos(θ/2) = 1 - quality/R
-        //
-        //  where θ is the angle swept by a chord with the desired "quality".
-        //
-        // # line segments to approximate with the specified quality:
-        //   N = ⌈SweepAngle/θ⌉
-
-        let (steps, delta) = if tolerance <= 0.0 || self.radius() <= tolerance {
-            (1, self.sweep_angle())
-        } else {
-            let cos_theta_on_two = 1.0 - tolerance / self.radius();
-            let theta = cos_theta_on_two.acos() * 2.0;
-            let line_segment_count = self.sweep_angle().get() / theta;
-
-            // make sure we always have at least 2 points
-            let line_segment_count = f64::max(line_segment_count, 2.0);
-            let actual_step = self.sweep_angle() / line_segment_count;
-
-            (line_segment_count.ceil().abs() as usize, actual_step)
-        };
+        let (steps, delta) = steps_for_radius(self.radius(), self.sweep_angle(), tolerance);
 
         ApproximatedArc {
             i: 0,
@@ -84,6 +116,93 @@ impl<Space> Approximate<Space> for Arc<Space> {
     }
 }
 
+impl<Space> Approximate<Space> for EllipticalArc<Space> {
+    type Iter = ApproximatedEllipticalArc<Space>;
+
+    fn approximate(&self, tolerance: f64) -> Self::Iter {
+        // The chord-error bound only holds exactly for a circle, so drive
+        // the step count off the larger of the two radii. That's a
+        // conservative choice (the tighter-curved axis never needs more
+        // steps than this), but it guarantees the error never exceeds
+        // `tolerance` anywhere along the ellipse.
+        let (steps, delta) = steps_for_radius(self.max_radius(), self.sweep_angle(), tolerance);
+
+        ApproximatedEllipticalArc {
+            i: 0,
+            steps,
+            step_size: delta,
+            arc: *self,
+        }
+    }
+}
+
+/// The recursion depth at which Bézier flattening gives up and emits the
+/// curve's endpoint regardless of flatness, guaranteeing termination on
+/// degenerate or cusped inputs.
+const MAX_BEZIER_DEPTH: u32 = 32;
+
+impl<Space> Approximate<Space> for QuadraticBezier<Space> {
+    type Iter = vec::IntoIter<Point2D<f64, Space>>;
+
+    fn approximate(&self, tolerance: f64) -> Self::Iter {
+        let mut points = Vec::new();
+        self.approximate_into(tolerance, &mut points);
+        points.into_iter()
+    }
+
+    fn approximate_into(&self, tolerance: f64, out: &mut Vec<Point2D<f64, Space>>) {
+        out.push(self.start);
+        flatten_quadratic(self, tolerance, 0, out);
+    }
+}
+
+fn flatten_quadratic<Space>(
+    curve: &QuadraticBezier<Space>,
+    tolerance: f64,
+    depth: u32,
+    out: &mut Vec<Point2D<f64, Space>>,
+) {
+    if depth >= MAX_BEZIER_DEPTH || curve.flatness() <= tolerance {
+        out.push(curve.end);
+        return;
+    }
+
+    let (left, right) = curve.split(0.5);
+    flatten_quadratic(&left, tolerance, depth + 1, out);
+    flatten_quadratic(&right, tolerance, depth + 1, out);
+}
+
+impl<Space> Approximate<Space> for CubicBezier<Space> {
+    type Iter = vec::IntoIter<Point2D<f64, Space>>;
+
+    fn approximate(&self, tolerance: f64) -> Self::Iter {
+        let mut points = Vec::new();
+        self.approximate_into(tolerance, &mut points);
+        points.into_iter()
+    }
+
+    fn approximate_into(&self, tolerance: f64, out: &mut Vec<Point2D<f64, Space>>) {
+        out.push(self.start);
+        flatten_cubic(self, tolerance, 0, out);
+    }
+}
+
+fn flatten_cubic<Space>(
+    curve: &CubicBezier<Space>,
+    tolerance: f64,
+    depth: u32,
+    out: &mut Vec<Point2D<f64, Space>>,
+) {
+    if depth >= MAX_BEZIER_DEPTH || curve.flatness() <= tolerance {
+        out.push(curve.end);
+        return;
+    }
+
+    let (left, right) = curve.split(0.5);
+    flatten_cubic(&left, tolerance, depth + 1, out);
+    flatten_cubic(&right, tolerance, depth + 1, out);
+}
+
 /// An iterator over the points in an arc approximation.
 ///
 /// This shouldn't be used directly, you are probably looking for
@@ -112,6 +231,34 @@ impl<Space> Iterator for ApproximatedArc<Space> {
     }
 }
 
+/// An iterator over the points in an elliptical arc approximation.
+///
+/// This shouldn't be used directly, you are probably looking for
+/// `EllipticalArc::approximate()`.
+#[derive(Debug, Clone)]
+#[allow(missing_copy_implementations)] // iterators which are Copy are a footgun
+pub struct ApproximatedEllipticalArc<Space> {
+    i: usize,
+    steps: usize,
+    step_size: Angle,
+    arc: EllipticalArc<Space>,
+}
+
+impl<Space> Iterator for ApproximatedEllipticalArc<Space> {
+    type Item = Point2D<f64, Space>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.i > self.steps {
+            return None;
+        }
+
+        let angle = Angle::radians(self.i as f64 * self.step_size.radians);
+        let point = self.arc.point_at(angle);
+        self.i += 1;
+        Some(point)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -120,12 +267,7 @@ mod tests {
 
     #[test]
     fn approximate_arc_with_points() {
-        let arc = Arc::from_centre_radius(
-            Point::zero(),
-            100.0,
-            Angle::zero(),
-            Angle::frac_pi_2(),
-        );
+        let arc = Arc::from_centre_radius(Point::zero(), 100.0, Angle::zero(), Angle::frac_pi_2());
         let quality = 10.0;
 
         let pieces: Vec<_> = arc.approximate(quality).collect();
@@ -137,4 +279,56 @@ mod tests {
         assert_eq!(arc.start(), *pieces.first().unwrap());
         assert_eq!(arc.end(), *pieces.last().unwrap());
     }
+
+    #[test]
+    fn approximate_elliptical_arc_with_points() {
+        let arc = EllipticalArc::new(
+            Point::zero(),
+            euclid::default::Vector2D::new(100.0, 40.0),
+            Angle::zero(),
+            Angle::frac_pi_2(),
+            Angle::zero(),
+        );
+        let quality = 5.0;
+
+        let pieces: Vec<_> = arc.approximate(quality).collect();
+
+        assert!(pieces.len() >= 2);
+        assert_eq!(arc.start(), *pieces.first().unwrap());
+        assert_eq!(arc.end(), *pieces.last().unwrap());
+    }
+
+    #[test]
+    fn flattening_a_quadratic_bezier_hits_endpoints_and_subdivides() {
+        let curve = QuadraticBezier::new(
+            Point::zero(),
+            Point::new(50.0, 100.0),
+            Point::new(100.0, 0.0),
+        );
+        let tolerance = 0.5;
+
+        let pieces: Vec<_> = curve.approximate(tolerance).collect();
+
+        assert_eq!(curve.start, *pieces.first().unwrap());
+        assert_eq!(curve.end, *pieces.last().unwrap());
+        // A curve this sharply bent can't be flattened to a tight
+        // tolerance with just its two endpoints.
+        assert!(pieces.len() > 2);
+    }
+
+    #[test]
+    fn flattening_a_cubic_bezier_terminates_and_hits_endpoints() {
+        let curve = CubicBezier::new(
+            Point::zero(),
+            Point::new(0.0, 100.0),
+            Point::new(100.0, 100.0),
+            Point::new(100.0, 0.0),
+        );
+
+        let pieces: Vec<_> = curve.approximate(1.0).collect();
+
+        assert_eq!(curve.start, *pieces.first().unwrap());
+        assert_eq!(curve.end, *pieces.last().unwrap());
+        assert!(pieces.len() > 2);
+    }
 }
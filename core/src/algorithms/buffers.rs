@@ -0,0 +1,63 @@
+//! Reusable scratch buffers for [`Approximate`], so flattening many shapes
+//! in a hot loop (e.g. once per frame) doesn't allocate a fresh `Vec` every
+//! time — mirroring rosu-pp's `CurveBuffers`.
+
+use crate::algorithms::Approximate;
+use euclid::Point2D;
+
+/// A reusable buffer of approximated vertices.
+///
+/// Each call to [`CurveBuffers::approximate`] clears the buffer's contents
+/// but keeps its allocated capacity, so repeated calls on shapes of similar
+/// complexity settle into steady-state with no further allocation.
+#[derive(Debug, Clone)]
+pub struct CurveBuffers<Space> {
+    points: Vec<Point2D<f64, Space>>,
+}
+
+impl<Space> CurveBuffers<Space> {
+    /// Create an empty set of buffers.
+    pub fn new() -> Self {
+        CurveBuffers { points: Vec::new() }
+    }
+
+    /// Approximate `shape` into this buffer's scratch space, returning the
+    /// resulting vertices as a slice.
+    pub fn approximate<T>(&mut self, shape: &T, tolerance: f64) -> &[Point2D<f64, Space>]
+    where
+        T: Approximate<Space> + ?Sized,
+    {
+        self.points.clear();
+        shape.approximate_into(tolerance, &mut self.points);
+        &self.points
+    }
+}
+
+impl<Space> Default for CurveBuffers<Space> {
+    fn default() -> Self {
+        CurveBuffers::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::primitives::Arc;
+    use crate::Angle;
+
+    type Point = euclid::default::Point2D<f64>;
+
+    #[test]
+    fn reusing_the_buffer_keeps_its_capacity() {
+        let arc = Arc::from_centre_radius(Point::zero(), 100.0, Angle::zero(), Angle::frac_pi_2());
+        let mut buffers = CurveBuffers::new();
+
+        let first_len = buffers.approximate(&arc, 10.0).len();
+        let capacity_after_first = buffers.points.capacity();
+
+        let second_len = buffers.approximate(&arc, 10.0).len();
+
+        assert_eq!(first_len, second_len);
+        assert!(buffers.points.capacity() >= capacity_after_first);
+    }
+}
@@ -0,0 +1,176 @@
+//! Shape-level measurements — perimeter, area, bounding box, winding —
+//! mirroring kurbo's `Shape` trait but built entirely on top of
+//! [`Approximate`]'s flattened vertices, so accuracy stays controlled by
+//! the same `tolerance` the caller already uses for rendering.
+
+use crate::algorithms::Approximate;
+use euclid::{Box2D, Point2D};
+
+/// Shape-level measurements, computed from the same vertices
+/// [`Approximate::approximate`] would produce for `tolerance`, so their
+/// accuracy is controlled by that tolerance.
+pub trait Shape<Space> {
+    /// The total length of the shape's boundary.
+    fn perimeter(&self, tolerance: f64) -> f64;
+
+    /// The area enclosed by the shape.
+    fn area(&self, tolerance: f64) -> f64;
+
+    /// An axis-aligned box containing the shape.
+    fn bounding_box(&self, tolerance: f64) -> Box2D<f64, Space>;
+
+    /// The winding number of the shape's boundary around `point` — how
+    /// many times it wraps around `point`, and in which direction.
+    fn winding(&self, point: Point2D<f64, Space>, tolerance: f64) -> i32;
+}
+
+impl<Space, T: Approximate<Space>> Shape<Space> for T {
+    fn perimeter(&self, tolerance: f64) -> f64 {
+        perimeter_of(self.approximate(tolerance))
+    }
+
+    fn area(&self, tolerance: f64) -> f64 {
+        area_of(self.approximate(tolerance))
+    }
+
+    fn bounding_box(&self, tolerance: f64) -> Box2D<f64, Space> {
+        bounding_box_of(self.approximate(tolerance))
+    }
+
+    fn winding(&self, point: Point2D<f64, Space>, tolerance: f64) -> i32 {
+        winding_of(self.approximate(tolerance), point)
+    }
+}
+
+/// Sum the chord lengths between consecutive approximated vertices.
+fn perimeter_of<Space>(points: impl Iterator<Item = Point2D<f64, Space>>) -> f64 {
+    let mut total = 0.0;
+    let mut previous = None;
+
+    for point in points {
+        if let Some(previous) = previous {
+            total += (point - previous).length();
+        }
+        previous = Some(point);
+    }
+
+    total
+}
+
+/// Fold the approximated vertices into their axis-aligned bounding box.
+fn bounding_box_of<Space>(
+    mut points: impl Iterator<Item = Point2D<f64, Space>>,
+) -> Box2D<f64, Space> {
+    let first = points.next().unwrap_or_else(Point2D::zero);
+
+    points.fold(Box2D::new(first, first), |bounds, point| {
+        bounds.union(&Box2D::new(point, point))
+    })
+}
+
+/// The shoelace formula, applied to the polygon closed by joining the last
+/// approximated vertex back to the first.
+fn area_of<Space>(points: impl Iterator<Item = Point2D<f64, Space>>) -> f64 {
+    let points: Vec<_> = points.collect();
+    if points.len() < 3 {
+        return 0.0;
+    }
+
+    let mut sum = 0.0;
+    for i in 0..points.len() {
+        let a = points[i];
+        let b = points[(i + 1) % points.len()];
+        sum += a.x * b.y - b.x * a.y;
+    }
+
+    (sum / 2.0).abs()
+}
+
+/// Count signed crossings of the polygon (closed the same way as
+/// [`area_of`]) against a ray cast from `query`, using Dan Sunday's winding
+/// number algorithm.
+fn winding_of<Space>(
+    points: impl Iterator<Item = Point2D<f64, Space>>,
+    query: Point2D<f64, Space>,
+) -> i32 {
+    let points: Vec<_> = points.collect();
+    if points.len() < 2 {
+        return 0;
+    }
+
+    let edges = points
+        .windows(2)
+        .map(|pair| (pair[0], pair[1]))
+        .chain(std::iter::once((points[points.len() - 1], points[0])));
+
+    let mut winding = 0;
+    for (a, b) in edges {
+        if a.y <= query.y {
+            if b.y > query.y && is_left(a, b, query) > 0.0 {
+                winding += 1;
+            }
+        } else if b.y <= query.y && is_left(a, b, query) < 0.0 {
+            winding -= 1;
+        }
+    }
+
+    winding
+}
+
+/// `> 0` if `query` is left of the line `a -> b`, `< 0` if it's to the
+/// right, `0` if it's exactly on the line.
+fn is_left<Space>(
+    a: Point2D<f64, Space>,
+    b: Point2D<f64, Space>,
+    query: Point2D<f64, Space>,
+) -> f64 {
+    (b.x - a.x) * (query.y - a.y) - (query.x - a.x) * (b.y - a.y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::primitives::Line;
+
+    type Point = euclid::default::Point2D<f64>;
+
+    #[test]
+    fn perimeter_of_a_square_made_from_lines() {
+        // Shape only needs an Approximate impl; reuse Line (a loop of four
+        // of them approximates a square) rather than pulling in Arc.
+        let corners = [
+            Point::new(0.0, 0.0),
+            Point::new(10.0, 0.0),
+            Point::new(10.0, 10.0),
+            Point::new(0.0, 10.0),
+        ];
+
+        let total: f64 = corners
+            .windows(2)
+            .map(|w| Line::new(w[0], w[1]).perimeter(0.1))
+            .sum::<f64>()
+            + Line::new(corners[3], corners[0]).perimeter(0.1);
+
+        assert!((total - 40.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn bounding_box_of_a_line() {
+        let line = Line::new(Point::new(-5.0, 2.0), Point::new(10.0, -3.0));
+
+        let bounds = line.bounding_box(0.1);
+
+        assert_eq!(bounds.min, Point::new(-5.0, -3.0));
+        assert_eq!(bounds.max, Point::new(10.0, 2.0));
+    }
+
+    #[test]
+    fn bounding_box_of_a_line_not_straddling_the_origin() {
+        let line = Line::new(Point::new(5.0, 5.0), Point::new(10.0, 10.0));
+
+        let bounds = line.bounding_box(0.1);
+
+        assert_eq!(bounds.min, Point::new(5.0, 5.0));
+        assert_eq!(bounds.max, Point::new(10.0, 10.0));
+    }
+}
@@ -0,0 +1,290 @@
+//! Arc-welding: the inverse of [`Approximate`](crate::algorithms::Approximate).
+//!
+//! Where `Approximate` turns an `Arc` into a run of points, [`weld`] takes a
+//! run of points (typically already-flattened lines and arcs from another
+//! tool) and greedily replaces runs of them with `Arc`s wherever a circle
+//! fits within `resolution`, giving a compressed G-code-style path.
+
+use crate::{
+    ops,
+    primitives::{Arc, Line},
+    Angle,
+};
+use euclid::Point2D;
+use std::f64::consts::TAU;
+
+/// A segment of a welded path: either a straight [`Line`] or a circular
+/// [`Arc`], whichever best fit the run of points it replaces.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Segment<Space> {
+    Line(Line<Space>),
+    Arc(Arc<Space>),
+}
+
+/// The minimum number of points a run must span before it's allowed to
+/// collapse into an `Arc`; shorter runs fall back to `Line`s since three
+/// points can always be fit with *some* circle.
+const MIN_ARC_POINTS: usize = 4;
+
+/// Weld a sequence of points into a compressed path of [`Line`]s and
+/// [`Arc`]s.
+///
+/// A window is grown over `points`, fitting a circle through the window's
+/// first, middle and last point. The window keeps extending while every
+/// point inside it stays within `resolution` of that circle, the arc's
+/// winding direction stays consistent, and (if given) the radius stays
+/// under `max_radius`. When the fit breaks, the last window that still fit
+/// is emitted as an `Arc` — or as one `Line` per hop, if the run was too
+/// short — and a new window starts from its end point.
+pub fn weld<Space: Copy>(
+    points: &[Point2D<f64, Space>],
+    resolution: f64,
+    max_radius: Option<f64>,
+) -> Vec<Segment<Space>> {
+    let mut segments = Vec::new();
+    let mut start = 0;
+
+    while start + 1 < points.len() {
+        let mut end = start + 1;
+        let mut fit: Option<(Circle<Space>, f64)> = None;
+
+        while end + 1 < points.len() {
+            let candidate_end = end + 1;
+
+            match fit_circle(points, start, candidate_end, max_radius) {
+                Some(circle) => {
+                    match winding(&circle, &points[start..=candidate_end], resolution) {
+                        Some(sign) => {
+                            fit = Some((circle, sign));
+                            end = candidate_end;
+                        }
+                        None => break,
+                    }
+                }
+                None => break,
+            }
+        }
+
+        match fit {
+            Some((circle, sign)) if end - start + 1 >= MIN_ARC_POINTS => {
+                segments.push(Segment::Arc(circle.to_arc(
+                    points[start],
+                    points[end],
+                    sign,
+                )));
+            }
+            _ => {
+                // The run was too short to bother with an arc. Emit a Line
+                // per original segment rather than one Line spanning the
+                // whole run: the points fit a *circle*, so a single chord
+                // across them isn't guaranteed to stay within `resolution`.
+                for pair in points[start..=end].windows(2) {
+                    segments.push(Segment::Line(Line::new(pair[0], pair[1])));
+                }
+            }
+        }
+
+        start = end;
+    }
+
+    segments
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Circle<Space> {
+    centre: Point2D<f64, Space>,
+    radius: f64,
+}
+
+impl<Space> Circle<Space> {
+    fn to_arc(
+        &self,
+        start: Point2D<f64, Space>,
+        end: Point2D<f64, Space>,
+        winding_sign: f64,
+    ) -> Arc<Space> {
+        let start_angle = ops::atan2(start.y - self.centre.y, start.x - self.centre.x);
+        let end_angle = ops::atan2(end.y - self.centre.y, end.x - self.centre.x);
+
+        // Normalize the sweep so its sign matches the direction the window
+        // actually wound in, rather than always taking the short way round.
+        let mut sweep = end_angle - start_angle;
+        if winding_sign > 0.0 {
+            while sweep <= 0.0 {
+                sweep += TAU;
+            }
+        } else {
+            while sweep >= 0.0 {
+                sweep -= TAU;
+            }
+        }
+
+        Arc::from_centre_radius(
+            self.centre,
+            self.radius,
+            Angle::radians(start_angle),
+            Angle::radians(sweep),
+        )
+    }
+}
+
+/// Fit a circle through `points[start]`, the point halfway between `start`
+/// and `end`, and `points[end]`, via the classic perpendicular-bisector
+/// intersection (computed here as the circumcenter of the three points).
+fn fit_circle<Space: Copy>(
+    points: &[Point2D<f64, Space>],
+    start: usize,
+    end: usize,
+    max_radius: Option<f64>,
+) -> Option<Circle<Space>> {
+    let mid = start + (end - start) / 2;
+    if mid == start || mid == end {
+        return None;
+    }
+
+    let centre = circumcenter(points[start], points[mid], points[end])?;
+    let radius = (points[start] - centre).length();
+
+    if let Some(max_radius) = max_radius {
+        if radius > max_radius {
+            return None;
+        }
+    }
+
+    Some(Circle { centre, radius })
+}
+
+fn circumcenter<Space>(
+    a: Point2D<f64, Space>,
+    b: Point2D<f64, Space>,
+    c: Point2D<f64, Space>,
+) -> Option<Point2D<f64, Space>> {
+    let d = 2.0 * (a.x * (b.y - c.y) + b.x * (c.y - a.y) + c.x * (a.y - b.y));
+    if d.abs() < f64::EPSILON {
+        // The three points are (nearly) collinear; no circle passes
+        // through all of them.
+        return None;
+    }
+
+    let a_sq = a.x * a.x + a.y * a.y;
+    let b_sq = b.x * b.x + b.y * b.y;
+    let c_sq = c.x * c.x + c.y * c.y;
+
+    let ux = (a_sq * (b.y - c.y) + b_sq * (c.y - a.y) + c_sq * (a.y - b.y)) / d;
+    let uy = (a_sq * (c.x - b.x) + b_sq * (a.x - c.x) + c_sq * (b.x - a.x)) / d;
+
+    Some(Point2D::new(ux, uy))
+}
+
+/// Check that every point in `window` stays within `resolution` of
+/// `circle`, and that consecutive points wind around its centre with a
+/// consistent sign. Returns the winding sign if both hold.
+fn winding<Space: Copy>(
+    circle: &Circle<Space>,
+    window: &[Point2D<f64, Space>],
+    resolution: f64,
+) -> Option<f64> {
+    if !window
+        .iter()
+        .all(|&p| ((p - circle.centre).length() - circle.radius).abs() <= resolution)
+    {
+        return None;
+    }
+
+    let mut sign = 0.0;
+    for pair in window.windows(2) {
+        let from = pair[0] - circle.centre;
+        let to = pair[1] - circle.centre;
+        let cross = from.x * to.y - from.y * to.x;
+
+        if cross.abs() < f64::EPSILON {
+            continue;
+        }
+
+        let this_sign = cross.signum();
+        if sign == 0.0 {
+            sign = this_sign;
+        } else if this_sign != sign {
+            return None;
+        }
+    }
+
+    if sign == 0.0 {
+        None
+    } else {
+        Some(sign)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Point = euclid::default::Point2D<f64>;
+
+    fn quarter_circle_points(centre: Point, radius: f64, steps: usize) -> Vec<Point> {
+        (0..=steps)
+            .map(|i| {
+                let theta = (i as f64 / steps as f64) * std::f64::consts::FRAC_PI_2;
+                centre + euclid::default::Vector2D::new(theta.cos(), theta.sin()) * radius
+            })
+            .collect()
+    }
+
+    #[test]
+    fn welds_a_quarter_circle_into_a_single_arc() {
+        let points = quarter_circle_points(Point::zero(), 50.0, 16);
+
+        let segments = weld(&points, 0.01, None);
+
+        assert_eq!(segments.len(), 1);
+        match segments[0] {
+            Segment::Arc(arc) => {
+                assert!((arc.radius() - 50.0).abs() < 0.01);
+            }
+            Segment::Line(_) => panic!("expected an Arc, got a Line"),
+        }
+    }
+
+    #[test]
+    fn falls_back_to_lines_for_collinear_points() {
+        let points = vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(2.0, 0.0),
+            Point::new(3.0, 0.0),
+        ];
+
+        let segments = weld(&points, 0.01, None);
+
+        // No triple of collinear points fits a circle, so the window never
+        // grows past two points and every hop becomes its own Line.
+        assert_eq!(segments.len(), points.len() - 1);
+        assert!(segments.iter().all(|s| matches!(s, Segment::Line(_))));
+    }
+
+    #[test]
+    fn short_non_collinear_runs_keep_every_point() {
+        // Only 3 points: they fit a circle, but the run is shorter than
+        // MIN_ARC_POINTS, so it must fall back to one Line per hop rather
+        // than a single Line that skips the middle point.
+        let points = vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(2.0, 0.0),
+        ];
+
+        let segments = weld(&points, 0.01, None);
+
+        assert_eq!(segments.len(), 2);
+        match (&segments[0], &segments[1]) {
+            (Segment::Line(a), Segment::Line(b)) => {
+                assert_eq!(a.start, points[0]);
+                assert_eq!(a.end, points[1]);
+                assert_eq!(b.start, points[1]);
+                assert_eq!(b.end, points[2]);
+            }
+            _ => panic!("expected two Line segments"),
+        }
+    }
+}
@@ -0,0 +1,52 @@
+//! Transcendental math routing: `std` when it's available, `libm`
+//! otherwise.
+//!
+//! Approximation leans on `acos`/`sin_cos`/`atan2`, whose `std`
+//! implementations have unspecified precision and aren't available in
+//! `no_std` at all. Every transcendental call in [`approximate`] and its
+//! supporting modules goes through here instead of calling the `f64`
+//! methods directly, so approximation output is bit-reproducible across
+//! platforms and the crate can be built for embedded/CNC targets without
+//! `std`, by enabling the `libm` feature.
+//!
+//! [`approximate`]: crate::algorithms::approximate
+
+#[cfg(feature = "libm")]
+pub(crate) fn acos(x: f64) -> f64 {
+    libm::acos(x)
+}
+
+#[cfg(not(feature = "libm"))]
+pub(crate) fn acos(x: f64) -> f64 {
+    x.acos()
+}
+
+#[cfg(feature = "libm")]
+pub(crate) fn sin_cos(x: f64) -> (f64, f64) {
+    libm::sincos(x)
+}
+
+#[cfg(not(feature = "libm"))]
+pub(crate) fn sin_cos(x: f64) -> (f64, f64) {
+    x.sin_cos()
+}
+
+#[cfg(feature = "libm")]
+pub(crate) fn atan2(y: f64, x: f64) -> f64 {
+    libm::atan2(y, x)
+}
+
+#[cfg(not(feature = "libm"))]
+pub(crate) fn atan2(y: f64, x: f64) -> f64 {
+    y.atan2(x)
+}
+
+#[cfg(feature = "libm")]
+pub(crate) fn sqrt(x: f64) -> f64 {
+    libm::sqrt(x)
+}
+
+#[cfg(not(feature = "libm"))]
+pub(crate) fn sqrt(x: f64) -> f64 {
+    x.sqrt()
+}
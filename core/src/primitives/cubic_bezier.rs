@@ -0,0 +1,57 @@
+use crate::primitives::quadratic_bezier::distance_to_chord;
+use euclid::Point2D;
+
+/// A cubic Bézier curve: `start` and `end` points pulled toward two control
+/// points, `ctrl1` and `ctrl2`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CubicBezier<Space> {
+    pub start: Point2D<f64, Space>,
+    pub ctrl1: Point2D<f64, Space>,
+    pub ctrl2: Point2D<f64, Space>,
+    pub end: Point2D<f64, Space>,
+}
+
+impl<Space> CubicBezier<Space> {
+    /// Create a new [`CubicBezier`] from its four control points.
+    pub fn new(
+        start: Point2D<f64, Space>,
+        ctrl1: Point2D<f64, Space>,
+        ctrl2: Point2D<f64, Space>,
+        end: Point2D<f64, Space>,
+    ) -> Self {
+        CubicBezier {
+            start,
+            ctrl1,
+            ctrl2,
+            end,
+        }
+    }
+
+    /// Split this curve at `t` via De Casteljau's algorithm, returning the
+    /// two halves.
+    pub fn split(&self, t: f64) -> (Self, Self) {
+        let p01 = self.start.lerp(self.ctrl1, t);
+        let p12 = self.ctrl1.lerp(self.ctrl2, t);
+        let p23 = self.ctrl2.lerp(self.end, t);
+        let p012 = p01.lerp(p12, t);
+        let p123 = p12.lerp(p23, t);
+        let mid = p012.lerp(p123, t);
+
+        (
+            CubicBezier::new(self.start, p01, p012, mid),
+            CubicBezier::new(mid, p123, p23, self.end),
+        )
+    }
+
+    /// How far the control points deviate from the chord `start -> end`.
+    ///
+    /// Used as the flatness test when deciding whether to subdivide
+    /// further, following the usual cubic flattening bound: the curve is
+    /// flat enough once *both* control points are within tolerance of the
+    /// baseline.
+    pub fn flatness(&self) -> f64 {
+        let d1 = distance_to_chord(self.ctrl1, self.start, self.end);
+        let d2 = distance_to_chord(self.ctrl2, self.start, self.end);
+        d1.max(d2)
+    }
+}
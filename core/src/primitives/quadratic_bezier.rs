@@ -0,0 +1,62 @@
+use euclid::Point2D;
+
+/// A quadratic Bézier curve: `start` and `end` points pulled toward a
+/// single `ctrl` point.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct QuadraticBezier<Space> {
+    pub start: Point2D<f64, Space>,
+    pub ctrl: Point2D<f64, Space>,
+    pub end: Point2D<f64, Space>,
+}
+
+impl<Space> QuadraticBezier<Space> {
+    /// Create a new [`QuadraticBezier`] from its three control points.
+    pub fn new(
+        start: Point2D<f64, Space>,
+        ctrl: Point2D<f64, Space>,
+        end: Point2D<f64, Space>,
+    ) -> Self {
+        QuadraticBezier { start, ctrl, end }
+    }
+
+    /// Split this curve at `t` via De Casteljau's algorithm, returning the
+    /// two halves.
+    pub fn split(&self, t: f64) -> (Self, Self) {
+        let start_ctrl = self.start.lerp(self.ctrl, t);
+        let ctrl_end = self.ctrl.lerp(self.end, t);
+        let mid = start_ctrl.lerp(ctrl_end, t);
+
+        (
+            QuadraticBezier::new(self.start, start_ctrl, mid),
+            QuadraticBezier::new(mid, ctrl_end, self.end),
+        )
+    }
+
+    /// How far `ctrl` deviates from the chord `start -> end`.
+    ///
+    /// Used as the flatness test when deciding whether to subdivide
+    /// further: a curve whose control point barely leaves the chord is
+    /// already indistinguishable from a straight line at the tolerance in
+    /// question.
+    pub fn flatness(&self) -> f64 {
+        distance_to_chord(self.ctrl, self.start, self.end)
+    }
+}
+
+/// The perpendicular distance from `point` to the line through `start` and
+/// `end`, falling back to the distance to `start` if they coincide.
+pub(crate) fn distance_to_chord<Space>(
+    point: Point2D<f64, Space>,
+    start: Point2D<f64, Space>,
+    end: Point2D<f64, Space>,
+) -> f64 {
+    let chord = end - start;
+    let length = chord.length();
+
+    if length <= f64::EPSILON {
+        return (point - start).length();
+    }
+
+    let offset = point - start;
+    (chord.x * offset.y - chord.y * offset.x).abs() / length
+}
@@ -0,0 +1,206 @@
+//! Conversions from SVG path arc segments into this crate's primitives.
+
+use crate::{
+    ops,
+    primitives::{Arc, EllipticalArc},
+    Angle,
+};
+use euclid::{Point2D, Vector2D};
+
+impl<Space> EllipticalArc<Space> {
+    /// Build an [`EllipticalArc`] from the endpoint-notation parameters of
+    /// an SVG `A`/`a` path arc segment.
+    ///
+    /// This follows the conversion laid out in the SVG spec, sections
+    /// [F.6.5] and [F.6.6]: the two endpoints are transformed into the
+    /// (unrotated) ellipse's coordinate system, out-of-range radii are
+    /// scaled up until they fit, the centre is solved for, and
+    /// `start_angle`/`sweep_angle` are derived from the `large_arc` and
+    /// `sweep` flags.
+    ///
+    /// [F.6.5]: https://www.w3.org/TR/SVG/implnote.html#ArcConversionEndpointToCenter
+    /// [F.6.6]: https://www.w3.org/TR/SVG/implnote.html#ArcCorrectionOutOfRangeRadii
+    ///
+    /// Returns `None` for the degenerate cases the spec says to treat as a
+    /// no-op or a straight line instead of an arc: `from == to`, or either
+    /// radius being zero. Without this guard the centre/angle solve divides
+    /// by zero and produces `NaN`, which would otherwise flow silently into
+    /// [`Approximate`](crate::algorithms::Approximate).
+    pub fn from_svg_endpoint(
+        from: Point2D<f64, Space>,
+        to: Point2D<f64, Space>,
+        radii: Vector2D<f64, Space>,
+        x_axis_rotation: Angle,
+        large_arc: bool,
+        sweep: bool,
+    ) -> Option<Self> {
+        if from == to {
+            return None;
+        }
+
+        // F.6.6: correct out-of-range radii by scaling them up.
+        let mut rx = radii.x.abs();
+        let mut ry = radii.y.abs();
+        if rx <= f64::EPSILON || ry <= f64::EPSILON {
+            return None;
+        }
+
+        // F.6.5.1: compute (x1', y1'), the endpoint halfway vector rotated
+        // into the ellipse's (unrotated) coordinate system.
+        let (sin_phi, cos_phi) = ops::sin_cos(x_axis_rotation.radians);
+        let half_delta = (from - to) / 2.0;
+        let x1 = cos_phi * half_delta.x + sin_phi * half_delta.y;
+        let y1 = -sin_phi * half_delta.x + cos_phi * half_delta.y;
+
+        let lambda = (x1 * x1) / (rx * rx) + (y1 * y1) / (ry * ry);
+        if lambda > 1.0 {
+            let scale = ops::sqrt(lambda);
+            rx *= scale;
+            ry *= scale;
+        }
+
+        // F.6.5.2: solve for (cx', cy'), the centre in the ellipse's
+        // (unrotated) coordinate system.
+        let rx_sq = rx * rx;
+        let ry_sq = ry * ry;
+        let x1_sq = x1 * x1;
+        let y1_sq = y1 * y1;
+
+        let numerator = (rx_sq * ry_sq) - (rx_sq * y1_sq) - (ry_sq * x1_sq);
+        let denominator = (rx_sq * y1_sq) + (ry_sq * x1_sq);
+        let co = ops::sqrt(numerator.max(0.0) / denominator)
+            * if large_arc == sweep { -1.0 } else { 1.0 };
+
+        let cx1 = co * (rx * y1 / ry);
+        let cy1 = co * -(ry * x1 / rx);
+
+        // F.6.5.3: transform (cx', cy') back into the original coordinate
+        // system to get the actual centre.
+        let centre = Point2D::new(
+            cos_phi * cx1 - sin_phi * cy1 + (from.x + to.x) / 2.0,
+            sin_phi * cx1 + cos_phi * cy1 + (from.y + to.y) / 2.0,
+        );
+
+        // F.6.5.4 - F.6.5.6: derive start_angle and sweep_angle from the
+        // angle between the x-axis and the vectors to each endpoint.
+        let start_angle = angle_between(
+            Vector2D::new(1.0, 0.0),
+            Vector2D::new((x1 - cx1) / rx, (y1 - cy1) / ry),
+        );
+
+        let mut sweep_angle = angle_between(
+            Vector2D::new((x1 - cx1) / rx, (y1 - cy1) / ry),
+            Vector2D::new((-x1 - cx1) / rx, (-y1 - cy1) / ry),
+        );
+
+        let full_turn = Angle::two_pi();
+        if !sweep && sweep_angle.get() > 0.0 {
+            sweep_angle -= full_turn;
+        } else if sweep && sweep_angle.get() < 0.0 {
+            sweep_angle += full_turn;
+        }
+
+        Some(EllipticalArc::new(
+            centre,
+            Vector2D::new(rx, ry),
+            start_angle,
+            sweep_angle,
+            x_axis_rotation,
+        ))
+    }
+}
+
+impl<Space> Arc<Space> {
+    /// Build a circular [`Arc`] from the endpoint-notation parameters of an
+    /// SVG `A`/`a` path arc segment, using [`EllipticalArc::from_svg_endpoint`]
+    /// under the hood.
+    ///
+    /// This takes a single `radius`, so it only handles circular arcs; for
+    /// a general ellipse use [`EllipticalArc::from_svg_endpoint`] directly.
+    ///
+    /// Returns `None` in the same degenerate cases
+    /// [`EllipticalArc::from_svg_endpoint`] does.
+    pub fn from_svg_endpoint(
+        from: Point2D<f64, Space>,
+        to: Point2D<f64, Space>,
+        radius: f64,
+        large_arc: bool,
+        sweep: bool,
+    ) -> Option<Self> {
+        let elliptical = EllipticalArc::from_svg_endpoint(
+            from,
+            to,
+            Vector2D::new(radius, radius),
+            Angle::zero(),
+            large_arc,
+            sweep,
+        )?;
+
+        Some(Arc::from_centre_radius(
+            elliptical.centre(),
+            elliptical.radii().x,
+            elliptical.start_angle(),
+            elliptical.sweep_angle(),
+        ))
+    }
+}
+
+/// The signed angle swept from `from` to `to`, in the range `(-π, π]`
+/// (the range of `atan2`).
+fn angle_between<Space>(from: Vector2D<f64, Space>, to: Vector2D<f64, Space>) -> Angle {
+    let dot = from.x * to.x + from.y * to.y;
+    let cross = from.x * to.y - from.y * to.x;
+    Angle::radians(ops::atan2(cross, dot))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Point = euclid::default::Point2D<f64>;
+
+    #[test]
+    fn converts_a_quarter_circle_arc() {
+        let arc = EllipticalArc::from_svg_endpoint(
+            Point::new(100.0, 0.0),
+            Point::new(0.0, 100.0),
+            Vector2D::new(100.0, 100.0),
+            Angle::zero(),
+            false,
+            true,
+        )
+        .unwrap();
+
+        assert!((arc.centre() - Point::zero()).length() < 1e-9);
+        assert!((arc.radii().x - 100.0).abs() < 1e-9);
+        assert!((arc.radii().y - 100.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn zero_length_segment_is_a_no_op() {
+        let point = Point::new(10.0, 10.0);
+
+        assert!(EllipticalArc::from_svg_endpoint(
+            point,
+            point,
+            Vector2D::new(5.0, 5.0),
+            Angle::zero(),
+            false,
+            true,
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn zero_radius_is_not_representable_as_an_arc() {
+        assert!(EllipticalArc::from_svg_endpoint(
+            Point::new(0.0, 0.0),
+            Point::new(10.0, 0.0),
+            Vector2D::new(0.0, 5.0),
+            Angle::zero(),
+            false,
+            true,
+        )
+        .is_none());
+    }
+}